@@ -41,4 +41,217 @@ macro_rules! ptest {
             }
         )*
     )
-}
\ No newline at end of file
+}
+
+/// Generates a matrix (cartesian product) of test functions for a set of
+/// named parameters.
+///
+/// Where [`ptest!`] requires writing out every parameter tuple by hand,
+/// `ptest_matrix!` takes a list of candidate values per parameter and
+/// generates one test per combination. For `N` parameters with `k` values
+/// each, this produces `k^N` tests, each its own `#[test] fn`, so that
+/// `cargo test` reports exactly which combination failed.
+///
+/// Generated test names are derived from the parameter names and values,
+/// e.g. `a: (0, 1), b: (true, false)` produces `test_fn_a_0_b_true`,
+/// `test_fn_a_0_b_false`, `test_fn_a_1_b_true`, `test_fn_a_1_b_false`. A
+/// negative numeric literal is sanitized to a `neg_`-prefixed fragment
+/// (e.g. `-8` becomes `neg_8`), since stable `macro_rules!` cannot splice
+/// a bare `-` token into an identifier. A parenthesized, bracketed, or
+/// braced value (e.g. `(1 + 2)`) has no text that can be reused this way
+/// either, so it's instead named after its position among that
+/// parameter's values. Building these names relies on the
+/// [`paste`](https://docs.rs/paste) crate, since stable `macro_rules!`
+/// cannot otherwise glue a non-`ident` value token (like a literal) onto
+/// an identifier.
+///
+/// If a parameter's value list is empty, that parameter contributes zero
+/// combinations, so the whole matrix collapses to zero generated tests.
+/// Each value expression is substituted textually into every generated
+/// test function that uses it, so it is re-evaluated once per test rather
+/// than being shared or cloned; value expressions don't need to be `Copy`
+/// or `Clone`, but they are re-run as many times as they appear across the
+/// matrix.
+///
+/// # Examples
+///
+/// ```no_run
+/// # #[macro_use] extern crate corrosion;
+/// fn test_add_commutes(a : i32, b : i32) {
+///   assert_eq!(a + b, b + a);
+/// }
+///
+/// ptest_matrix!(test_add_commutes [
+///     a : (0, -8, 51432),
+///     b : (5, 3, 765437)
+/// ]);
+///
+/// # fn main() {
+/// # }
+///
+/// ```
+#[macro_export]
+macro_rules! ptest_matrix {
+    ($fn_name : ident [$($params : tt)*]) => {
+        $crate::__ptest_matrix_pop!($fn_name () [$($params)*]);
+    };
+}
+
+/// Pops the next `name : (values)` parameter off the front of the
+/// remaining parameter list, wrapping whatever is left over into its own
+/// token tree so it can be threaded through [`__ptest_matrix_values`]
+/// without being torn apart by the value loop there (mixing two
+/// differently-sized repetitions in one expansion is rejected by
+/// `macro_rules!`).
+#[doc(hidden)]
+#[macro_export]
+macro_rules! __ptest_matrix_pop {
+    ($fn_name : ident $acc : tt []) => {
+        $crate::__ptest_matrix_unwind!($fn_name $acc [] [] []);
+    };
+    ($fn_name : ident $acc : tt
+        [$name : ident : ($($values : tt)*) $(, $($rest : tt)*)?])
+    => {
+        $crate::__ptest_matrix_values!(
+            $fn_name $acc $name [$($($rest)*)?] [] [] $($values)*);
+    };
+}
+
+/// Walks the raw value tokens of the current parameter, one comma-
+/// separated value at a time, pairing each with the token(s)
+/// [`__ptest_matrix_emit`] should splice into the generated test name.
+/// `$tally` grows by one marker per value seen so far, so its length is
+/// that value's position within the parameter.
+///
+/// A bare negative literal (`-8`) is kept as-is for the actual value but
+/// paired with a sanitized `neg_`-prefixed name fragment, since `paste!`
+/// cannot glue a `-` token onto an identifier. A value that is itself a
+/// single parenthesized, bracketed, or braced group (e.g. `(1 + 2)`) has
+/// no canonical text to reuse as a name either, since `paste!` rejects any
+/// such group outright, so it's instead named by its position among this
+/// parameter's values, encoded as one `x` per earlier value (e.g. the
+/// third such value becomes `valuexx`). Everything else — a literal,
+/// identifier, or other single-token value — is already a valid
+/// identifier fragment and is reused for both, exactly as before. A
+/// multi-token expression that isn't already wrapped in a single group
+/// (e.g. a bare `1 + 2` or a method call like `one()`) has no such
+/// fragment to fall back on and will still fail to compile; wrap it in
+/// parentheses to name it by position instead, as above.
+#[doc(hidden)]
+#[macro_export]
+macro_rules! __ptest_matrix_values {
+    ($fn_name : ident $acc : tt $name : ident $remaining : tt $tally : tt
+        [$($value : tt $value_name : tt)*])
+    => {
+        $(
+            $crate::__ptest_matrix_pop!(
+                $fn_name [$acc, $name, $value, $value_name] $remaining);
+        )*
+    };
+    ($fn_name : ident $acc : tt $name : ident $remaining : tt [$($tally : tt)*]
+        [$($pair : tt)*] - $lit : literal $(, $($rest : tt)*)?)
+    => {
+        $crate::__ptest_matrix_values!(
+            $fn_name $acc $name $remaining [$($tally)* x]
+            [$($pair)* (-$lit) (neg_ $lit)] $($($rest)*)?);
+    };
+    ($fn_name : ident $acc : tt $name : ident $remaining : tt [$($tally : tt)*]
+        [$($pair : tt)*] ($($inner : tt)*) $(, $($rest : tt)*)?)
+    => {
+        $crate::__ptest_matrix_values!(
+            $fn_name $acc $name $remaining [$($tally)* x]
+            [$($pair)* (($($inner)*)) (value $($tally)*)] $($($rest)*)?);
+    };
+    ($fn_name : ident $acc : tt $name : ident $remaining : tt [$($tally : tt)*]
+        [$($pair : tt)*] [$($inner : tt)*] $(, $($rest : tt)*)?)
+    => {
+        $crate::__ptest_matrix_values!(
+            $fn_name $acc $name $remaining [$($tally)* x]
+            [$($pair)* ([$($inner)*]) (value $($tally)*)] $($($rest)*)?);
+    };
+    ($fn_name : ident $acc : tt $name : ident $remaining : tt [$($tally : tt)*]
+        [$($pair : tt)*] { $($inner : tt)* } $(, $($rest : tt)*)?)
+    => {
+        $crate::__ptest_matrix_values!(
+            $fn_name $acc $name $remaining [$($tally)* x]
+            [$($pair)* ({ $($inner)* }) (value $($tally)*)] $($($rest)*)?);
+    };
+    ($fn_name : ident $acc : tt $name : ident $remaining : tt [$($tally : tt)*]
+        [$($pair : tt)*] $value : expr $(, $($rest : tt)*)?)
+    => {
+        $crate::__ptest_matrix_values!(
+            $fn_name $acc $name $remaining [$($tally)* x]
+            [$($pair)* ($value) ($value)] $($($rest)*)?);
+    };
+}
+
+/// Unwinds the nested `[prev, name, value, value_name]` accumulator one
+/// parameter at a time into parallel, equal-length lists (names, values,
+/// and name fragments) that [`__ptest_matrix_emit`] can finally zip
+/// together.
+#[doc(hidden)]
+#[macro_export]
+macro_rules! __ptest_matrix_unwind {
+    ($fn_name : ident () [$($name : ident)*] [$($value : expr),*]
+        [$($value_name : tt)*])
+    => {
+        $crate::__ptest_matrix_emit!(
+            $fn_name [$($name)*] [$($value),*] [$($value_name)*]);
+    };
+    ($fn_name : ident [$prev : tt, $name : ident, $value : expr, $value_name : tt]
+        [$($acc_name : ident)*] [$($acc_value : expr),*]
+        [$($acc_value_name : tt)*])
+    => {
+        $crate::__ptest_matrix_unwind!(
+            $fn_name
+            $prev
+            [$name $($acc_name)*]
+            [$value $(, $acc_value)*]
+            [$value_name $($acc_value_name)*]
+        );
+    };
+}
+
+/// Emits the generated test function for one fully-resolved combination
+/// of parameter values.
+#[doc(hidden)]
+#[macro_export]
+macro_rules! __ptest_matrix_emit {
+    ($fn_name : ident [$($name : ident)*] [$($value : expr),*]
+        [$($value_name : tt)*])
+    => {
+        $crate::__ptest_matrix_emit_flatten!(
+            $fn_name [$($value),*] [] [$(_ $name _ $value_name)*]);
+    };
+}
+
+/// Flattens the (possibly multi-token) name fragments gathered by
+/// [`__ptest_matrix_values`] into a single token stream, then hands it to
+/// [`paste::paste!`] to build the generated test's identifier. This
+/// indirection is what lets a sanitized fragment like `neg_8` (two
+/// tokens wrapped together so it can travel through the accumulator as a
+/// single `tt`) get spliced as plain, unwrapped tokens.
+#[doc(hidden)]
+#[macro_export]
+macro_rules! __ptest_matrix_emit_flatten {
+    ($fn_name : ident [$($value : expr),*] [$($flat : tt)*] [])
+    => {
+        paste::paste! {
+            #[test]
+            fn [<$fn_name $($flat)*>]() {
+                $fn_name($($value),*)
+            }
+        }
+    };
+    ($fn_name : ident $values : tt [$($flat : tt)*]
+        [($($inner : tt)*) $($rest : tt)*])
+    => {
+        $crate::__ptest_matrix_emit_flatten!(
+            $fn_name $values [$($flat)* $($inner)*] [$($rest)*]);
+    };
+    ($fn_name : ident $values : tt [$($flat : tt)*] [$tok : tt $($rest : tt)*])
+    => {
+        $crate::__ptest_matrix_emit_flatten!(
+            $fn_name $values [$($flat)* $tok] [$($rest)*]);
+    };
+}